@@ -0,0 +1,819 @@
+use crate::goal::{Goal, GoalError, GoalId};
+use crate::holder::GoalHolder;
+use crate::status::{Outcome, Status};
+use crate::view::GoalContainer;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::time::Duration;
+
+/// Counts of how each goal in a run was resolved.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DriverSummary {
+    pub succeeded: usize,
+    pub failed: usize,
+    pub skipped: usize,
+}
+
+/// A goal's fingerprint and outcome from the last time its cache entry was
+/// written, keyed by goal name in the on-disk cache file.
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    fingerprint: String,
+    succeeded: bool,
+}
+
+/// Reads a `Driver` cache file written by [`save_cache`], tolerating a
+/// missing or unreadable file by treating it as an empty cache (e.g. the
+/// first run).
+fn load_cache(path: &Path) -> HashMap<String, CacheEntry> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return HashMap::new();
+    };
+
+    contents
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '\t');
+            let name = fields.next()?.to_string();
+            let fingerprint = fields.next()?.to_string();
+            let succeeded = fields.next()? == "success";
+            Some((name, CacheEntry { fingerprint, succeeded }))
+        })
+        .collect()
+}
+
+/// Writes a `name\tfingerprint\t(success|failed)` line per entry. Errors are
+/// swallowed: a cache that fails to persist just costs the next run its
+/// incremental skips, rather than failing the whole build.
+fn save_cache(path: &Path, cache: &HashMap<String, CacheEntry>) {
+    let mut contents = String::new();
+    for (name, entry) in cache {
+        contents.push_str(name);
+        contents.push('\t');
+        contents.push_str(&entry.fingerprint);
+        contents.push('\t');
+        contents.push_str(if entry.succeeded { "success" } else { "failed" });
+        contents.push('\n');
+    }
+    let _ = fs::write(path, contents);
+}
+
+/// Carries out the actual work behind a goal.
+///
+/// The driver calls this once a goal's dependencies have all finished, and
+/// records whatever [`Outcome`] it returns as that goal's final status.
+pub trait GoalAction<H: GoalHolder> {
+    fn run(&mut self, id: GoalId, holder: &Arc<RwLock<H>>) -> Outcome;
+}
+
+/// Runs a [`GoalContainer`]'s goal graph to completion in dependency order.
+///
+/// Modeled on rebel's `CompletionState`: goals are scheduled once every
+/// child they depend on has reached [`Status::Finished`] (`deps_satisfied`),
+/// transitioning each one `Waiting -> InProgress -> Finished` in turn. A
+/// failure anywhere beneath a goal short-circuits that goal, and everything
+/// above it, as `Finished(Outcome::Skipped)` without ever invoking their
+/// actions.
+pub struct Driver<'a, H: GoalHolder, A> {
+    container: &'a GoalContainer<H>,
+    action: A,
+    tasks_done: HashSet<GoalId>,
+    concurrency: usize,
+    cache_path: Option<PathBuf>,
+    force_rebuild: bool,
+}
+
+impl<'a, H, A> Driver<'a, H, A>
+where
+    H: GoalHolder,
+    A: GoalAction<H>,
+{
+    pub fn new(container: &'a GoalContainer<H>, action: A) -> Self {
+        Self {
+            container,
+            action,
+            tasks_done: HashSet::new(),
+            concurrency: 1,
+            cache_path: None,
+            force_rebuild: false,
+        }
+    }
+
+    /// Runs up to `n` independent goals at a time, round-robin across
+    /// whichever branches are ready, instead of draining one subtree to
+    /// completion before starting the next. `n == 1` (the default)
+    /// reproduces the original strictly sequential order.
+    pub fn with_concurrency(mut self, n: usize) -> Self {
+        self.concurrency = n.max(1);
+        self
+    }
+
+    /// Persists a `name -> (fingerprint, outcome)` cache to `path` between
+    /// runs. A goal whose [`Goal::fingerprint`] matches its entry from the
+    /// last successful run, and whose dependencies didn't themselves re-run,
+    /// is finished as `Finished(Skipped)` without invoking its action.
+    pub fn with_cache_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.cache_path = Some(path.into());
+        self
+    }
+
+    /// Ignores any cache file set via [`Driver::with_cache_file`] for this
+    /// run, forcing every goal to re-run regardless of its fingerprint. The
+    /// cache file (if any) is still overwritten with this run's results.
+    pub fn force_rebuild(mut self) -> Self {
+        self.force_rebuild = true;
+        self
+    }
+
+    /// Runs every goal in the container to completion, returning a summary
+    /// of how many succeeded, failed, or were skipped.
+    pub fn run(self) -> Result<DriverSummary, GoalError>
+    where
+        H: Send + Sync,
+        A: Clone + Send,
+    {
+        if self.concurrency <= 1 {
+            self.run_sequential()
+        } else {
+            self.run_parallel()
+        }
+    }
+
+    fn run_sequential(mut self) -> Result<DriverSummary, GoalError> {
+        let holder = self.container.holder().clone();
+
+        let root_id = {
+            let guard = holder.read().expect("Failed to get holder (poisoned)");
+            *guard.get_goal_id(self.container.root_goal().name())?
+        };
+
+        let mut goals = {
+            let guard = holder.read().expect("Failed to get holder (poisoned)");
+            guard.get_all_children(&root_id)?
+        };
+        goals.insert(root_id);
+
+        let loaded_cache = match &self.cache_path {
+            Some(path) if !self.force_rebuild => load_cache(path),
+            _ => HashMap::new(),
+        };
+        let mut cache = loaded_cache;
+        let mut reran: HashSet<GoalId> = HashSet::new();
+
+        let mut summary = DriverSummary::default();
+        let mut blocked: HashSet<GoalId> = HashSet::new();
+
+        while self.tasks_done.len() < goals.len() {
+            let runnable: Vec<GoalId> = {
+                let guard = holder.read().expect("Failed to get holder (poisoned)");
+                let mut runnable = Vec::new();
+                for id in &goals {
+                    if self.tasks_done.contains(id) {
+                        continue;
+                    }
+                    if Self::deps_satisfied(&*guard, id, &self.tasks_done)? {
+                        runnable.push(*id);
+                    }
+                }
+                runnable
+            };
+
+            if runnable.is_empty() {
+                // Nothing left is runnable (e.g. a cyclic graph); stop rather
+                // than spin forever.
+                break;
+            }
+
+            for id in runnable {
+                let blocked_by_child = {
+                    let guard = holder.read().expect("Failed to get holder (poisoned)");
+                    guard
+                        .get_direct_children(&id)?
+                        .into_iter()
+                        .any(|child| blocked.contains(&child))
+                };
+
+                if blocked_by_child {
+                    let mut guard = holder.write().expect("Failed to get holder (poisoned)");
+                    guard.set_status(id, Status::Finished(Outcome::Skipped))?;
+                    blocked.insert(id);
+                    self.tasks_done.insert(id);
+                    summary.skipped += 1;
+                    continue;
+                }
+
+                let (name, is_cache_hit) = {
+                    let guard = holder.read().expect("Failed to get holder (poisoned)");
+                    let name = guard.get_goal_name(id)?.clone();
+                    let is_cache_hit = Self::cache_hit(&*guard, &id, &name, &cache, &reran)?;
+                    (name, is_cache_hit)
+                };
+
+                if is_cache_hit {
+                    let mut guard = holder.write().expect("Failed to get holder (poisoned)");
+                    guard.set_status(id, Status::Finished(Outcome::Skipped))?;
+                    self.tasks_done.insert(id);
+                    summary.skipped += 1;
+                    continue;
+                }
+
+                {
+                    let mut guard = holder.write().expect("Failed to get holder (poisoned)");
+                    guard.set_status(id, Status::InProgress)?;
+                }
+
+                let outcome = self.action.run(id, &holder);
+                reran.insert(id);
+
+                match &outcome {
+                    Outcome::Success => summary.succeeded += 1,
+                    Outcome::Skipped => summary.skipped += 1,
+                    Outcome::Failed(_) => {
+                        summary.failed += 1;
+                        blocked.insert(id);
+                    }
+                }
+
+                if let Some(fingerprint) = holder
+                    .read()
+                    .expect("Failed to get holder (poisoned)")
+                    .get_goal_fingerprint(id)?
+                {
+                    cache.insert(
+                        name,
+                        CacheEntry {
+                            fingerprint: fingerprint.clone(),
+                            succeeded: matches!(outcome, Outcome::Success),
+                        },
+                    );
+                }
+
+                {
+                    let mut guard = holder.write().expect("Failed to get holder (poisoned)");
+                    guard.set_status(id, Status::Finished(outcome))?;
+                }
+
+                self.tasks_done.insert(id);
+            }
+        }
+
+        if let Some(path) = &self.cache_path {
+            save_cache(path, &cache);
+        }
+
+        Ok(summary)
+    }
+
+    /// Whether `id` can be served from `cache` as unchanged: it reported a
+    /// fingerprint, that fingerprint matches its last *successful* run, and
+    /// none of its dependencies actually re-ran this run (which would
+    /// otherwise invalidate anything derived from them).
+    fn cache_hit(
+        holder: &H,
+        id: &GoalId,
+        name: &str,
+        cache: &HashMap<String, CacheEntry>,
+        reran: &HashSet<GoalId>,
+    ) -> Result<bool, GoalError> {
+        let Some(fingerprint) = holder.get_goal_fingerprint(*id)? else {
+            return Ok(false);
+        };
+        let Some(entry) = cache.get(name) else {
+            return Ok(false);
+        };
+        if !entry.succeeded || entry.fingerprint != *fingerprint {
+            return Ok(false);
+        }
+
+        let cascaded = holder
+            .get_direct_children(id)?
+            .into_iter()
+            .any(|child| reran.contains(&child));
+        Ok(!cascaded)
+    }
+
+    /// Dispatches goals onto a bounded pool of `self.concurrency` workers.
+    ///
+    /// Every worker pulls from one shared FIFO ready queue, so whichever
+    /// goal has been runnable longest goes next regardless of which
+    /// independent subtree it belongs to — the fair interleaving a
+    /// MicroKanren-style `or`/`and` search gets from round-robining its
+    /// sub-searches, applied here to goal branches instead. `set_status` is
+    /// the synchronization point: a worker re-evaluates the ready set only
+    /// after publishing a goal's new status, so a parent is never queued
+    /// before every dependency is visibly `Finished`.
+    fn run_parallel(self) -> Result<DriverSummary, GoalError>
+    where
+        H: Send + Sync,
+        A: Clone + Send,
+    {
+        let holder = self.container.holder().clone();
+
+        let root_id = {
+            let guard = holder.read().expect("Failed to get holder (poisoned)");
+            *guard.get_goal_id(self.container.root_goal().name())?
+        };
+
+        let mut goals = {
+            let guard = holder.read().expect("Failed to get holder (poisoned)");
+            guard.get_all_children(&root_id)?
+        };
+        goals.insert(root_id);
+
+        let loaded_cache = match &self.cache_path {
+            Some(path) if !self.force_rebuild => load_cache(path),
+            _ => HashMap::new(),
+        };
+
+        let done: Mutex<HashSet<GoalId>> = Mutex::new(self.tasks_done);
+        let blocked: Mutex<HashSet<GoalId>> = Mutex::new(HashSet::new());
+        let ready: Mutex<VecDeque<GoalId>> = Mutex::new(VecDeque::new());
+        // Goals a worker has popped off `ready` but not yet marked `done`.
+        // Without this, the dependents re-check below (which only knows
+        // about `done` and `ready`) can't see that a goal is already being
+        // worked on, and will queue it a second time.
+        let dispatched: Mutex<HashSet<GoalId>> = Mutex::new(HashSet::new());
+        let summary = Mutex::new(DriverSummary::default());
+        let cache: Mutex<HashMap<String, CacheEntry>> = Mutex::new(loaded_cache);
+        let reran: Mutex<HashSet<GoalId>> = Mutex::new(HashSet::new());
+
+        {
+            let guard = holder.read().expect("Failed to get holder (poisoned)");
+            let done_guard = done.lock().expect("done set poisoned");
+            let mut ready_guard = ready.lock().expect("ready queue poisoned");
+            for id in &goals {
+                if !done_guard.contains(id) && Self::deps_satisfied(&*guard, id, &done_guard)? {
+                    ready_guard.push_back(*id);
+                }
+            }
+        }
+
+        let workers = self.concurrency.min(goals.len().max(1));
+
+        thread::scope(|scope| {
+            for _ in 0..workers {
+                let holder = holder.clone();
+                let mut action = self.action.clone();
+                let goals = &goals;
+                let done = &done;
+                let blocked = &blocked;
+                let ready = &ready;
+                let dispatched = &dispatched;
+                let summary = &summary;
+                let cache = &cache;
+                let reran = &reran;
+
+                scope.spawn(move || loop {
+                    if done.lock().expect("done set poisoned").len() >= goals.len() {
+                        return;
+                    }
+
+                    // Pop and mark dispatched under the same locks (in the
+                    // same dispatched-then-ready order as the re-check
+                    // below), so no re-check can see the goal in neither.
+                    let popped = {
+                        let mut dispatched_guard = dispatched.lock().expect("dispatched set poisoned");
+                        let popped = ready.lock().expect("ready queue poisoned").pop_front();
+                        if let Some(id) = popped {
+                            dispatched_guard.insert(id);
+                        }
+                        popped
+                    };
+                    let Some(id) = popped else {
+                        thread::sleep(Duration::from_millis(1));
+                        continue;
+                    };
+
+                    let blocked_by_child = {
+                        let guard = holder.read().expect("Failed to get holder (poisoned)");
+                        let blocked_guard = blocked.lock().expect("blocked set poisoned");
+                        guard
+                            .get_direct_children(&id)
+                            .expect("goal disappeared mid-run")
+                            .into_iter()
+                            .any(|child| blocked_guard.contains(&child))
+                    };
+
+                    let (name, is_cache_hit) = {
+                        let guard = holder.read().expect("Failed to get holder (poisoned)");
+                        let name = guard
+                            .get_goal_name(id)
+                            .expect("goal disappeared mid-run")
+                            .clone();
+                        let is_cache_hit = if blocked_by_child {
+                            false
+                        } else {
+                            let cache_guard = cache.lock().expect("cache poisoned");
+                            let reran_guard = reran.lock().expect("reran set poisoned");
+                            Self::cache_hit(&*guard, &id, &name, &cache_guard, &reran_guard)
+                                .expect("goal disappeared mid-run")
+                        };
+                        (name, is_cache_hit)
+                    };
+
+                    if blocked_by_child {
+                        holder
+                            .write()
+                            .expect("Failed to get holder (poisoned)")
+                            .set_status(id, Status::Finished(Outcome::Skipped))
+                            .expect("goal disappeared mid-run");
+                        blocked.lock().expect("blocked set poisoned").insert(id);
+                        summary.lock().expect("summary poisoned").skipped += 1;
+                    } else if is_cache_hit {
+                        holder
+                            .write()
+                            .expect("Failed to get holder (poisoned)")
+                            .set_status(id, Status::Finished(Outcome::Skipped))
+                            .expect("goal disappeared mid-run");
+                        summary.lock().expect("summary poisoned").skipped += 1;
+                    } else {
+                        holder
+                            .write()
+                            .expect("Failed to get holder (poisoned)")
+                            .set_status(id, Status::InProgress)
+                            .expect("goal disappeared mid-run");
+
+                        let outcome = action.run(id, &holder);
+                        reran.lock().expect("reran set poisoned").insert(id);
+
+                        match &outcome {
+                            Outcome::Success => summary.lock().expect("summary poisoned").succeeded += 1,
+                            Outcome::Skipped => summary.lock().expect("summary poisoned").skipped += 1,
+                            Outcome::Failed(_) => {
+                                summary.lock().expect("summary poisoned").failed += 1;
+                                blocked.lock().expect("blocked set poisoned").insert(id);
+                            }
+                        }
+
+                        if let Some(fingerprint) = holder
+                            .read()
+                            .expect("Failed to get holder (poisoned)")
+                            .get_goal_fingerprint(id)
+                            .expect("goal disappeared mid-run")
+                        {
+                            cache.lock().expect("cache poisoned").insert(
+                                name,
+                                CacheEntry {
+                                    fingerprint: fingerprint.clone(),
+                                    succeeded: matches!(outcome, Outcome::Success),
+                                },
+                            );
+                        }
+
+                        holder
+                            .write()
+                            .expect("Failed to get holder (poisoned)")
+                            .set_status(id, Status::Finished(outcome))
+                            .expect("goal disappeared mid-run");
+                    }
+
+                    done.lock().expect("done set poisoned").insert(id);
+                    dispatched.lock().expect("dispatched set poisoned").remove(&id);
+
+                    // This goal finishing may have unblocked siblings or a
+                    // parent; re-check every not-yet-ready goal now that the
+                    // status above is visible to every worker.
+                    let guard = holder.read().expect("Failed to get holder (poisoned)");
+                    let done_snapshot = done.lock().expect("done set poisoned").clone();
+                    let dispatched_guard = dispatched.lock().expect("dispatched set poisoned");
+                    let mut ready_guard = ready.lock().expect("ready queue poisoned");
+                    for candidate in goals.iter() {
+                        if done_snapshot.contains(candidate)
+                            || ready_guard.contains(candidate)
+                            || dispatched_guard.contains(candidate)
+                        {
+                            continue;
+                        }
+                        if Self::deps_satisfied(&*guard, candidate, &done_snapshot)
+                            .unwrap_or(false)
+                        {
+                            ready_guard.push_back(*candidate);
+                        }
+                    }
+                });
+            }
+        });
+
+        if let Some(path) = &self.cache_path {
+            save_cache(path, &cache.into_inner().expect("cache poisoned"));
+        }
+
+        Ok(summary.into_inner().expect("summary poisoned"))
+    }
+
+    /// Mirrors rebel's `deps_satisfied`: a goal is runnable once every one
+    /// of its direct children is already `tasks_done`.
+    fn deps_satisfied(
+        holder: &H,
+        id: &GoalId,
+        tasks_done: &HashSet<GoalId>,
+    ) -> Result<bool, GoalError> {
+        Ok(holder
+            .get_direct_children(id)?
+            .into_iter()
+            .all(|child| tasks_done.contains(&child)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goal::{DefaultGoal, Goal};
+    use crate::holder::DefaultHolder;
+    use crate::view::GoalContainer;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn root_id(container: &GoalContainer<DefaultHolder>) -> GoalId {
+        let holder = container.holder().read().expect("holder poisoned");
+        *holder
+            .get_goal_id(container.root_goal().name())
+            .expect("root goal was never registered")
+    }
+
+    #[derive(Clone)]
+    struct AlwaysSucceeds;
+
+    impl GoalAction<DefaultHolder> for AlwaysSucceeds {
+        fn run(&mut self, _id: GoalId, _holder: &Arc<RwLock<DefaultHolder>>) -> Outcome {
+            Outcome::Success
+        }
+    }
+
+    #[test]
+    fn empty_graph_is_a_no_op() {
+        let container = GoalContainer::new("root", DefaultHolder::default());
+
+        let summary = Driver::new(&container, AlwaysSucceeds).run().unwrap();
+
+        assert_eq!(
+            summary,
+            DriverSummary {
+                succeeded: 1,
+                failed: 0,
+                skipped: 0,
+            }
+        );
+    }
+
+    #[derive(Clone)]
+    struct RecordingAction(Arc<Mutex<Vec<GoalId>>>);
+
+    impl GoalAction<DefaultHolder> for RecordingAction {
+        fn run(&mut self, id: GoalId, _holder: &Arc<RwLock<DefaultHolder>>) -> Outcome {
+            self.0.lock().expect("order vec poisoned").push(id);
+            Outcome::Success
+        }
+    }
+
+    #[test]
+    fn concurrency_one_runs_dependencies_before_dependents() {
+        let container = GoalContainer::new("root", DefaultHolder::default());
+        let holder = container.holder().clone();
+        let root = root_id(&container);
+
+        let c1 = DefaultGoal::new("c1", &root, &holder);
+        let c1_id = holder.write().unwrap().add_goal(&c1).unwrap();
+
+        let mut c2 = DefaultGoal::new("c2", &root, &holder);
+        c2.depend_on(c1_id);
+        let c2_id = holder.write().unwrap().add_goal(&c2).unwrap();
+
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let summary = Driver::new(&container, RecordingAction(order.clone()))
+            .with_concurrency(1)
+            .run()
+            .unwrap();
+
+        assert_eq!(summary.succeeded, 3); // root, c1, c2
+
+        let order = order.lock().unwrap();
+        let c1_pos = order.iter().position(|id| *id == c1_id).unwrap();
+        let c2_pos = order.iter().position(|id| *id == c2_id).unwrap();
+        assert!(
+            c1_pos < c2_pos,
+            "c1 must run before the c2 that depends on it: {order:?}"
+        );
+    }
+
+    #[test]
+    fn concurrency_four_runs_dependencies_before_dependents() {
+        let container = GoalContainer::new("root", DefaultHolder::default());
+        let holder = container.holder().clone();
+        let root = root_id(&container);
+
+        let leaf_ids: Vec<GoalId> = (0..4)
+            .map(|i| {
+                let leaf = DefaultGoal::new(format!("leaf{i}"), &root, &holder);
+                holder.write().unwrap().add_goal(&leaf).unwrap()
+            })
+            .collect();
+
+        let mut dependent = DefaultGoal::new("dependent", &root, &holder);
+        for &leaf_id in &leaf_ids {
+            dependent.depend_on(leaf_id);
+        }
+        let dependent_id = holder.write().unwrap().add_goal(&dependent).unwrap();
+
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let summary = Driver::new(&container, RecordingAction(order.clone()))
+            .with_concurrency(4)
+            .run()
+            .unwrap();
+
+        assert_eq!(summary.succeeded, 6); // root, 4 leaves, dependent
+
+        let order = order.lock().unwrap();
+        let dependent_pos = order.iter().position(|id| *id == dependent_id).unwrap();
+        for &leaf_id in &leaf_ids {
+            let leaf_pos = order.iter().position(|id| *id == leaf_id).unwrap();
+            assert!(
+                leaf_pos < dependent_pos,
+                "every leaf must finish before the dependent that waits on it: {order:?}"
+            );
+        }
+    }
+
+    /// A goal whose fingerprint is fixed at construction, standing in for a
+    /// real [`Goal`] impl that hashes its own inputs.
+    struct FingerprintedGoal {
+        inner: DefaultGoal<DefaultHolder>,
+        fingerprint: String,
+    }
+
+    impl FingerprintedGoal {
+        fn new(
+            name: impl AsRef<str>,
+            parent: &GoalId,
+            holder: &Arc<RwLock<DefaultHolder>>,
+            fingerprint: impl Into<String>,
+        ) -> Self {
+            Self {
+                inner: DefaultGoal::new(name, parent, holder),
+                fingerprint: fingerprint.into(),
+            }
+        }
+
+        fn depend_on(&mut self, id: GoalId) {
+            self.inner.depend_on(id);
+        }
+    }
+
+    impl Goal<DefaultHolder> for FingerprintedGoal {
+        fn new(name: impl AsRef<str>, parent: &GoalId, holder: &Arc<RwLock<DefaultHolder>>) -> Self {
+            FingerprintedGoal::new(name, parent, holder, "")
+        }
+        fn name(&self) -> &String {
+            self.inner.name()
+        }
+        fn parent_goal(&self) -> Option<&GoalId> {
+            self.inner.parent_goal()
+        }
+        fn child_goals(&self) -> &[GoalId] {
+            self.inner.child_goals()
+        }
+        fn depends_on(&self) -> &[GoalId] {
+            self.inner.depends_on()
+        }
+        fn fingerprint(&self) -> Option<String> {
+            Some(self.fingerprint.clone())
+        }
+        fn start(&mut self) {
+            self.inner.start()
+        }
+        fn finish(self, outcome: Status) {
+            self.inner.finish(outcome)
+        }
+        fn sub_goal<G, F>(&mut self, name: impl AsRef<str>, goal: impl Into<Option<F>>) -> G
+        where
+            G: Goal<DefaultHolder>,
+            F: FnOnce(&DefaultHolder, &mut G),
+        {
+            self.inner.sub_goal(name, goal)
+        }
+    }
+
+    #[derive(Clone)]
+    struct CountingAction(Arc<Mutex<HashSet<GoalId>>>);
+
+    impl GoalAction<DefaultHolder> for CountingAction {
+        fn run(&mut self, id: GoalId, _holder: &Arc<RwLock<DefaultHolder>>) -> Outcome {
+            self.0.lock().expect("executed set poisoned").insert(id);
+            Outcome::Success
+        }
+    }
+
+    fn cache_test_path(name: &str) -> PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        std::env::temp_dir().join(format!(
+            "load-rs-{name}-{}-{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ))
+    }
+
+    #[test]
+    fn cache_invalidation_cascades_through_rerun_dependencies() {
+        let cache_path = cache_test_path("cache-cascade");
+
+        // First run: both goals execute and populate the cache.
+        {
+            let container = GoalContainer::new("root", DefaultHolder::default());
+            let holder = container.holder().clone();
+            let root = root_id(&container);
+
+            let dep = FingerprintedGoal::new("dep", &root, &holder, "v1");
+            let dep_id = holder.write().unwrap().add_goal(&dep).unwrap();
+
+            let mut downstream = FingerprintedGoal::new("downstream", &root, &holder, "same");
+            downstream.depend_on(dep_id);
+            holder.write().unwrap().add_goal(&downstream).unwrap();
+
+            let summary = Driver::new(&container, AlwaysSucceeds)
+                .with_cache_file(&cache_path)
+                .run()
+                .unwrap();
+            assert_eq!(summary.succeeded, 3); // root, dep, downstream
+        }
+
+        // Second run: `dep`'s fingerprint changed, so it re-runs. Even
+        // though `downstream`'s own fingerprint is unchanged, it must also
+        // re-run since its dependency did.
+        {
+            let container = GoalContainer::new("root", DefaultHolder::default());
+            let holder = container.holder().clone();
+            let root = root_id(&container);
+
+            let dep = FingerprintedGoal::new("dep", &root, &holder, "v2");
+            let dep_id = holder.write().unwrap().add_goal(&dep).unwrap();
+
+            let mut downstream = FingerprintedGoal::new("downstream", &root, &holder, "same");
+            downstream.depend_on(dep_id);
+            let downstream_id = holder.write().unwrap().add_goal(&downstream).unwrap();
+
+            let executed = Arc::new(Mutex::new(HashSet::new()));
+            Driver::new(&container, CountingAction(executed.clone()))
+                .with_cache_file(&cache_path)
+                .run()
+                .unwrap();
+
+            let executed = executed.lock().unwrap();
+            assert!(
+                executed.contains(&dep_id),
+                "dep's fingerprint changed, so it must re-run"
+            );
+            assert!(
+                executed.contains(&downstream_id),
+                "downstream must re-run because its dependency re-ran, even though its own fingerprint didn't change"
+            );
+        }
+
+        // Third run: nothing changed since the second run's cache was
+        // written, so both goals must be served as cache hits without
+        // invoking their actions.
+        {
+            let container = GoalContainer::new("root", DefaultHolder::default());
+            let holder = container.holder().clone();
+            let root = root_id(&container);
+
+            let dep = FingerprintedGoal::new("dep", &root, &holder, "v2");
+            let dep_id = holder.write().unwrap().add_goal(&dep).unwrap();
+
+            let mut downstream = FingerprintedGoal::new("downstream", &root, &holder, "same");
+            downstream.depend_on(dep_id);
+            let downstream_id = holder.write().unwrap().add_goal(&downstream).unwrap();
+
+            let executed = Arc::new(Mutex::new(HashSet::new()));
+            let summary = Driver::new(&container, CountingAction(executed.clone()))
+                .with_cache_file(&cache_path)
+                .run()
+                .unwrap();
+
+            assert_eq!(summary.skipped, 2); // dep, downstream (root has no fingerprint)
+
+            let executed = executed.lock().unwrap();
+            assert!(
+                !executed.contains(&dep_id),
+                "dep's fingerprint is unchanged, so it must be served from the cache"
+            );
+            assert!(
+                !executed.contains(&downstream_id),
+                "downstream's fingerprint is unchanged and dep didn't re-run, so it must be served from the cache"
+            );
+
+            let guard = holder.read().unwrap();
+            assert!(matches!(
+                guard.get_goal_outcome(dep_id).unwrap(),
+                Status::Finished(Outcome::Skipped)
+            ));
+            assert!(matches!(
+                guard.get_goal_outcome(downstream_id).unwrap(),
+                Status::Finished(Outcome::Skipped)
+            ));
+        }
+
+        let _ = fs::remove_file(&cache_path);
+    }
+}
@@ -35,6 +35,24 @@ pub trait Goal<Holder: GoalHolder>: Sized {
     fn name(&self) -> &String;
     fn parent_goal(&self) -> Option<&GoalId>;
     fn child_goals(&self) -> &[GoalId];
+    /// Sibling goals this one must wait on without owning them.
+    ///
+    /// Unlike `child_goals`, these are registered as [`crate::holder::GoalEdge::Depends`]
+    /// edges, so they're treated as scheduling dependencies but never change
+    /// `get_parent`.
+    fn depends_on(&self) -> &[GoalId] {
+        &[]
+    }
+
+    /// A content hash of whatever this goal's work actually depends on (its
+    /// source files, arguments, …), if it wants incremental-skip support.
+    ///
+    /// When `Some` and unchanged since the goal's last successful run (and
+    /// none of its dependencies re-ran), [`crate::driver::Driver`] finishes
+    /// it as `Finished(Skipped)` without invoking its [`crate::driver::GoalAction`].
+    fn fingerprint(&self) -> Option<String> {
+        None
+    }
 
     /// This goal has started
     fn start(&mut self);
@@ -44,7 +62,7 @@ pub trait Goal<Holder: GoalHolder>: Sized {
     /// Will panic if a sub goal hasn't finished and the given result is `Ok(())`
     fn finish(self, outcome: Status);
     /// Shortcut to finishing with an error
-    fn fail(self, error: impl Error + 'static) {
+    fn fail(self, error: impl Error + Send + Sync + 'static) {
         self.finish(Status::Finished(Failed(Box::new(error))))
     }
     /// Goal finishes as a success
@@ -69,6 +87,8 @@ pub enum GoalError {
     MissingGoal(GoalId),
     #[error("Missing goal (name = {0})")]
     MissingGoalName(String),
+    #[error("Adding this goal would create a cycle (cycle = {0:?})")]
+    CycleDetected(Vec<GoalId>),
     #[error(transparent)]
     IoError(#[from] std::io::Error),
     #[error(transparent)]
@@ -83,9 +103,20 @@ where
     parent_goal: GoalId,
     my_id: GoalId,
     child_goals: Vec<GoalId>,
+    depends_on: Vec<GoalId>,
     holder: Arc<RwLock<Holder>>,
 }
 
+impl<Holder> DefaultGoal<Holder>
+where
+    Holder: GoalHolder,
+{
+    /// Marks this goal as depending on `id` without taking ownership of it.
+    pub fn depend_on(&mut self, id: GoalId) {
+        self.depends_on.push(id);
+    }
+}
+
 impl<Holder> Goal<Holder> for DefaultGoal<Holder>
 where
     Holder: GoalHolder,
@@ -98,6 +129,7 @@ where
             parent_goal: *parent,
             my_id: GoalId::NONE,
             child_goals: vec![],
+            depends_on: vec![],
             holder: holder.clone(),
         }
     }
@@ -114,12 +146,47 @@ where
         self.child_goals.as_slice()
     }
 
+    fn depends_on(&self) -> &[GoalId] {
+        self.depends_on.as_slice()
+    }
+
     fn start(&mut self) {
-        todo!()
+        let mut holder = self.holder.write().expect("Failed to get holder (poisoned)");
+        let id = *holder
+            .get_goal_id(&self.name)
+            .expect("Goal not registered with its holder");
+        holder
+            .set_status(id, Status::InProgress)
+            .expect("Goal not registered with its holder");
     }
 
     fn finish(self, outcome: Status) {
-        todo!()
+        let mut holder = self.holder.write().expect("Failed to get holder (poisoned)");
+        let id = *holder
+            .get_goal_id(&self.name)
+            .expect("Goal not registered with its holder");
+
+        if matches!(outcome, Status::Finished(Success)) {
+            let unfinished_child = holder
+                .get_direct_children(&id)
+                .expect("Goal not registered with its holder")
+                .into_iter()
+                .any(|child| {
+                    !matches!(
+                        holder.get_goal_outcome(child).expect("Child goal disappeared"),
+                        Status::Finished(_)
+                    )
+                });
+            assert!(
+                !unfinished_child,
+                "Can not finish goal '{}' as successful while a sub goal hasn't finished",
+                self.name
+            );
+        }
+
+        holder
+            .set_status(id, outcome)
+            .expect("Goal not registered with its holder");
     }
 
     fn sub_goal<G, F>(&mut self, name: impl AsRef<str>, configure: impl Into<Option<F>>) -> G
@@ -146,7 +213,9 @@ where
             .write()
             .expect("Failed to get holder (poisoned)");
 
-        holder.add_goal(&goal);
+        holder
+            .add_goal(&goal)
+            .expect("Adding a sub goal created a cycle in the goal graph");
 
         goal
     }
@@ -174,6 +243,14 @@ impl<H: GoalHolder> Goal<H> for RootGoal<H> {
         self.inner_goal.child_goals()
     }
 
+    fn depends_on(&self) -> &[GoalId] {
+        self.inner_goal.depends_on()
+    }
+
+    fn fingerprint(&self) -> Option<String> {
+        self.inner_goal.fingerprint()
+    }
+
     fn start(&mut self) {
         self.inner_goal.start()
     }
@@ -195,6 +272,7 @@ impl<H: GoalHolder> RootGoal<H> {
                 parent_goal: GoalId::NONE,
                 my_id: GoalId::NONE,
                 child_goals: vec![],
+                depends_on: vec![],
                 holder: holder.clone()
             }
         }
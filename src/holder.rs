@@ -7,7 +7,22 @@ use std::borrow::Borrow;
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 use std::ops::Index;
-use crate::view::GoalHandler;
+use crate::view::{GoalHandler, NoopGoalHandler};
+
+/// The kind of relationship a goal graph edge represents.
+///
+/// Both kinds count as scheduling dependencies (a node must wait on both its
+/// `Parent`-owned children and anything it `Depends` on), but only `Parent`
+/// edges define the single-parent ownership tree that [`GoalHolder::get_parent`]
+/// walks.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum GoalEdge {
+    /// The source node owns the target node as a sub-goal.
+    Parent,
+    /// The source node must wait on the target node finishing, without
+    /// owning it.
+    Depends,
+}
 
 pub trait GoalHolder
 where
@@ -16,11 +31,54 @@ where
         + Index<String, Output = GoalId>
         + Index<&'a String, Output = GoalId>,
 {
-    fn add_goal<G: Goal<Self>>(&mut self, goal: &G) -> GoalId;
+    /// Registers `goal`, wiring up its parent/child/dependency edges.
+    ///
+    /// Fails with [`GoalError::CycleDetected`] (leaving the holder unchanged)
+    /// if doing so would make the goal graph cyclic.
+    fn add_goal<G: Goal<Self>>(&mut self, goal: &G) -> Result<GoalId, GoalError>;
     fn get_goal_outcome(&self, id: GoalId) -> Result<&Status, GoalError>;
-    fn get_goal_id(&self, id: &String) -> Result<&GoalId, GoalError>;
 
-    fn set_goal_status<G: Goal<Self>>(&mut self, id: &G, status: Status) -> Result<(), GoalError>;
+    /// The status explicitly set on `id` via [`GoalHolder::set_status`], or
+    /// `None` if it was never touched (as opposed to [`GoalHolder::get_goal_outcome`],
+    /// which falls back to a default status instead of distinguishing the two).
+    /// Used by [`GoalHolder::aggregate_status`] to tell "genuinely still
+    /// waiting" apart from "just never explicitly set".
+    fn get_explicit_status(&self, id: GoalId) -> Result<Option<&Status>, GoalError>;
+
+    /// Like [`GoalHolder::get_goal_outcome`], but for callers (like a
+    /// progress view) that want a meaningful status for an interior goal
+    /// whose own status was never explicitly set: falls back to the join of
+    /// its whole sub-tree via [`GoalHolder::aggregate_status`].
+    fn get_aggregate_status(&self, id: GoalId) -> Result<Status, GoalError> {
+        self.aggregate_status(id)
+    }
+    fn get_goal_id(&self, id: &String) -> Result<&GoalId, GoalError>;
+    /// The inverse of [`GoalHolder::get_goal_id`], for callers (like a
+    /// progress view) that only have a [`GoalId`] to work with.
+    fn get_goal_name(&self, id: GoalId) -> Result<&String, GoalError>;
+    /// The fingerprint the goal reported via [`Goal::fingerprint`] when it
+    /// was added, if any. Used by the execution driver to decide whether a
+    /// goal's work can be skipped as unchanged.
+    fn get_goal_fingerprint(&self, id: GoalId) -> Result<Option<&String>, GoalError>;
+
+    /// Returns a valid dependency order over the whole goal graph (dependencies
+    /// before dependents), or `GoalError::CycleDetected` if the graph is cyclic.
+    fn topological_order(&self) -> Result<Vec<GoalId>, GoalError>;
+
+    /// Records that `from` must wait on `on` finishing, without making `on`
+    /// a sub-goal of `from`. Use this for goals that depend on siblings they
+    /// don't own.
+    ///
+    /// Fails with [`GoalError::CycleDetected`] (leaving the holder unchanged)
+    /// if doing so would make the goal graph cyclic, same as [`GoalHolder::add_goal`].
+    fn add_dependency(&mut self, from: GoalId, on: GoalId) -> Result<(), GoalError>;
+
+    /// Sets the status of a goal by id, without requiring a live [`Goal`] instance.
+    ///
+    /// This is the primitive the execution driver uses to drive goals through
+    /// `Waiting -> InProgress -> Finished` once the goal itself has been consumed.
+    /// Reports the change to the holder's registered [`GoalHandler`].
+    fn set_status(&mut self, id: GoalId, status: Status) -> Result<(), GoalError>;
 
     fn all_goals(&self) -> Vec<GoalId>;
 
@@ -50,39 +108,184 @@ where
         Ok(output)
     }
 
-    fn all_children_finished(&self, id: &GoalId) -> Result<bool, GoalError> {
-        let children = self.get_all_children(id)?;
-        for child in children {
-            let outcome = self.get_goal_outcome(child)?;
-            match outcome {
-                Status::Finished(_) => {}
-                _ => return Ok(false),
+    /// Computes `id`'s status as the join of its own explicitly-set status
+    /// (if any) together with its whole sub-tree's, rather than relying
+    /// solely on whichever was (or wasn't) explicitly set on `id` itself.
+    ///
+    /// Runs a worklist fixpoint bottom-up from the leaves: each node's status
+    /// is [`Rank::combine`]d from its own explicit rank (if
+    /// [`GoalHolder::set_status`] was ever called on it directly) and its
+    /// children's (possibly themselves derived) ranks, and a node is only
+    /// re-joined when one of its children's derived status actually changes.
+    /// A node that was never explicitly set contributes nothing of its own,
+    /// so a goal's default `Waiting` doesn't drag down an otherwise-finished
+    /// sub-tree; but a goal explicitly `start()`ed or `fail()`/`skip()`ed
+    /// directly still shows as more than `Waiting`, even before any of its
+    /// children have moved. Once everything beneath an ancestor has
+    /// finished, a single failed leaf therefore dominates it in one O(V+E)
+    /// pass, instead of repeatedly rescanning whole subtrees; while any
+    /// descendant is still unfinished, the ancestor reads as `InProgress`.
+    fn aggregate_status(&self, id: GoalId) -> Result<Status, GoalError> {
+        let nodes = self.get_all_children(&id)?;
+
+        let mut children_of = HashMap::with_capacity(nodes.len());
+        let mut parents_of: HashMap<GoalId, Vec<GoalId>> = HashMap::new();
+        let mut own_rank = HashMap::with_capacity(nodes.len());
+        for &n in &nodes {
+            let children = self.get_direct_children(&n)?;
+            for &c in &children {
+                parents_of.entry(c).or_default().push(n);
+            }
+            children_of.insert(n, children);
+            own_rank.insert(n, self.get_explicit_status(n)?.map(Rank::of));
+        }
+
+        let mut rank = HashMap::with_capacity(nodes.len());
+        let mut worklist = Vec::new();
+        for &n in &nodes {
+            if children_of[&n].is_empty() {
+                rank.insert(n, Rank::combine(own_rank[&n], Rank::join([])));
+                worklist.push(n);
+            }
+        }
+
+        while let Some(n) = worklist.pop() {
+            let Some(parents) = parents_of.get(&n) else {
+                continue;
+            };
+            for &parent in parents {
+                let children = &children_of[&parent];
+                if !children.iter().all(|c| rank.contains_key(c)) {
+                    continue;
+                }
+                let children_join = Rank::join(children.iter().map(|c| rank[c]));
+                let joined = Rank::combine(own_rank[&parent], children_join);
+                if rank.get(&parent) != Some(&joined) {
+                    rank.insert(parent, joined);
+                    worklist.push(parent);
+                }
+            }
+        }
+
+        let final_rank = *rank.get(&id).ok_or(GoalError::MissingGoal(id))?;
+        Ok(final_rank.to_status())
+    }
+}
+
+/// A [`Status`] reduced to the information needed to join it with siblings,
+/// since `Finished(Failed)`'s inner error can't be cloned out of a child
+/// when deriving its parent's status.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Rank {
+    Waiting,
+    InProgress,
+    Finished(FinishedRank),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum FinishedRank {
+    Skipped,
+    Success,
+    Failed,
+}
+
+impl Rank {
+    fn of(status: &Status) -> Self {
+        match status {
+            Status::Waiting => Rank::Waiting,
+            Status::InProgress => Rank::InProgress,
+            Status::Finished(Outcome::Skipped) => Rank::Finished(FinishedRank::Skipped),
+            Status::Finished(Outcome::Success) => Rank::Finished(FinishedRank::Success),
+            Status::Finished(Outcome::Failed(_)) => Rank::Finished(FinishedRank::Failed),
+        }
+    }
+
+    fn to_status(self) -> Status {
+        match self {
+            Rank::Waiting => Status::Waiting,
+            Rank::InProgress => Status::InProgress,
+            Rank::Finished(FinishedRank::Skipped) => Status::Finished(Outcome::Skipped),
+            Rank::Finished(FinishedRank::Success) => Status::Finished(Outcome::Success),
+            Rank::Finished(FinishedRank::Failed) => {
+                Status::Finished(Outcome::Failed(Box::new(crate::status::DerivedFailure)))
             }
         }
-        Ok(true)
     }
 
-    fn any_child_errored(&self, id: &GoalId) -> Result<bool, GoalError> {
-        let children = self.get_all_children(id)?;
+    /// The join over a goal's children: `Waiting` only if every child is
+    /// still `Waiting`; `InProgress` as long as any child hasn't finished;
+    /// once every child is finished, any `Failed` dominates, then any
+    /// `Success` dominates a plain `Skipped`, and all-`Skipped` stays
+    /// `Skipped`.
+    fn join(children: impl IntoIterator<Item = Rank>) -> Rank {
+        let mut all_waiting = true;
+        let mut all_finished = true;
+        let mut any_failed = false;
+        let mut any_success = false;
+
         for child in children {
-            let outcome = self.get_goal_outcome(child)?;
-            match outcome {
-                Status::Finished(Outcome::Failed(_)) => return Ok(true),
-                _ => {}
+            match child {
+                Rank::Waiting => all_finished = false,
+                Rank::InProgress => {
+                    all_waiting = false;
+                    all_finished = false;
+                }
+                Rank::Finished(FinishedRank::Failed) => {
+                    all_waiting = false;
+                    any_failed = true;
+                }
+                Rank::Finished(FinishedRank::Success) => {
+                    all_waiting = false;
+                    any_success = true;
+                }
+                Rank::Finished(FinishedRank::Skipped) => {
+                    all_waiting = false;
+                }
             }
         }
-        Ok(false)
+
+        if all_waiting {
+            Rank::Waiting
+        } else if !all_finished {
+            Rank::InProgress
+        } else if any_failed {
+            Rank::Finished(FinishedRank::Failed)
+        } else if any_success {
+            Rank::Finished(FinishedRank::Success)
+        } else {
+            Rank::Finished(FinishedRank::Skipped)
+        }
+    }
+
+    /// Folds a node's own explicitly-set rank (if any) together with the
+    /// [`Rank::join`] of its children.
+    ///
+    /// A node that explicitly finished (`own` is `Finished`) dominates
+    /// outright, regardless of its children — a goal can `fail()`/`skip()`
+    /// itself without waiting on sub-goals that never ran. Otherwise `own`
+    /// (if set) is folded into the children's join like one more sibling, so
+    /// a goal `start()`ed directly still reads as at least `InProgress`
+    /// rather than `Waiting`. A node that was never explicitly set (`own` is
+    /// `None`) contributes nothing, leaving a pure children join.
+    fn combine(own: Option<Rank>, children_join: Rank) -> Rank {
+        match own {
+            Some(Rank::Finished(finished)) => Rank::Finished(finished),
+            Some(other) => Rank::join([other, children_join]),
+            None => children_join,
+        }
     }
 }
 
 #[derive(Debug)]
-pub struct DefaultHolder<G : GoalHandler<Self> = Self> {
+pub struct DefaultHolder<G : GoalHandler<Self> = NoopGoalHandler> {
     name_to_id: HashMap<String, GoalId>,
+    id_to_name: HashMap<GoalId, String>,
+    id_to_fingerprint: HashMap<GoalId, String>,
     all_ids: HashSet<GoalId>,
     goal_id_to_status: HashMap<GoalId, Status>,
     default_status: Status,
     next_id: usize,
-    goal_graph: DiGraphMap<GoalId, ()>,
+    goal_graph: DiGraphMap<GoalId, GoalEdge>,
     goal_handler: G
 }
 
@@ -110,8 +313,41 @@ impl<G : GoalHandler<Self>> Index<&String> for DefaultHolder<G> {
     }
 }
 
+impl<GH: GoalHandler<Self>> DefaultHolder<GH> {
+    /// Creates an empty holder that reports goal status changes to `goal_handler`.
+    pub fn new(goal_handler: GH) -> Self {
+        Self {
+            name_to_id: HashMap::new(),
+            id_to_name: HashMap::new(),
+            id_to_fingerprint: HashMap::new(),
+            all_ids: HashSet::new(),
+            goal_id_to_status: HashMap::new(),
+            default_status: Status::Waiting,
+            next_id: 1,
+            goal_graph: DiGraphMap::new(),
+            goal_handler,
+        }
+    }
+
+    /// Builds the `GoalError::CycleDetected` for the cycle that `start` is
+    /// part of, by taking the strongly-connected component containing it.
+    fn cycle_error(&self, start: GoalId) -> GoalError {
+        let cycle = petgraph::algo::tarjan_scc(&self.goal_graph)
+            .into_iter()
+            .find(|component| component.contains(&start))
+            .unwrap_or_else(|| vec![start]);
+        GoalError::CycleDetected(cycle)
+    }
+}
+
+impl<GH: GoalHandler<Self> + Default> Default for DefaultHolder<GH> {
+    fn default() -> Self {
+        Self::new(GH::default())
+    }
+}
+
 impl<GH : GoalHandler<Self>> GoalHolder for DefaultHolder<GH> {
-    fn add_goal<G: Goal<Self>>(&mut self, goal: &G) -> GoalId {
+    fn add_goal<G: Goal<Self>>(&mut self, goal: &G) -> Result<GoalId, GoalError> {
         let next_id = self.next_id;
         self.next_id += 1;
         let goal_id = GoalId::from(next_id);
@@ -125,18 +361,42 @@ impl<GH : GoalHandler<Self>> GoalHolder for DefaultHolder<GH> {
                 v.insert(goal_id);
             }
         };
+        self.id_to_name.insert(goal_id, goal.name().clone());
+        if let Some(fingerprint) = goal.fingerprint() {
+            self.id_to_fingerprint.insert(goal_id, fingerprint);
+        }
 
         self.goal_graph.add_node(goal_id);
+        self.all_ids.insert(goal_id);
 
         if let Some(&p_id) = goal.parent_goal() {
-            self.goal_graph.add_edge(p_id, goal_id, ());
+            self.goal_graph.add_edge(p_id, goal_id, GoalEdge::Parent);
         }
 
         for &c_id in goal.child_goals() {
-            self.goal_graph.add_edge(goal_id, c_id, ());
+            self.goal_graph.add_edge(goal_id, c_id, GoalEdge::Parent);
+        }
+
+        for &dep_id in goal.depends_on() {
+            self.goal_graph.add_edge(goal_id, dep_id, GoalEdge::Depends);
+        }
+
+        if let Err(cycle) = petgraph::algo::toposort(&self.goal_graph, None) {
+            let error = self.cycle_error(cycle.node_id());
+
+            self.goal_graph.remove_node(goal_id);
+            self.all_ids.remove(&goal_id);
+            self.name_to_id.remove(goal.name());
+            self.id_to_name.remove(&goal_id);
+            self.id_to_fingerprint.remove(&goal_id);
+
+            return Err(error);
         }
 
-        goal_id
+        let parent = goal.parent_goal().copied();
+        self.goal_handler.register_goal(goal_id, goal.name(), parent);
+
+        Ok(goal_id)
     }
 
     fn get_goal_outcome(&self, id: GoalId) -> Result<&Status, GoalError> {
@@ -149,19 +409,62 @@ impl<GH : GoalHandler<Self>> GoalHolder for DefaultHolder<GH> {
         }
     }
 
+    fn get_explicit_status(&self, id: GoalId) -> Result<Option<&Status>, GoalError> {
+        if !self.all_ids.contains(&id) {
+            return Err(GoalError::MissingGoal(id));
+        }
+        Ok(self.goal_id_to_status.get(&id))
+    }
+
     fn get_goal_id(&self, id: &String) -> Result<&GoalId, GoalError> {
         self.name_to_id
             .get(id)
             .ok_or(GoalError::MissingGoalName(id.to_string()))
     }
 
-    fn set_goal_status<G: Goal<Self>>(
-        &mut self,
-        goal: &G,
-        status: Status,
-    ) -> Result<(), GoalError> {
-        let name = goal.name();
-        let id = *self.get_goal_id(name)?;
+    fn get_goal_name(&self, id: GoalId) -> Result<&String, GoalError> {
+        self.id_to_name.get(&id).ok_or(GoalError::MissingGoal(id))
+    }
+
+    fn get_goal_fingerprint(&self, id: GoalId) -> Result<Option<&String>, GoalError> {
+        if !self.all_ids.contains(&id) {
+            return Err(GoalError::MissingGoal(id));
+        }
+        Ok(self.id_to_fingerprint.get(&id))
+    }
+
+    fn add_dependency(&mut self, from: GoalId, on: GoalId) -> Result<(), GoalError> {
+        if !self.goal_graph.contains_node(from) {
+            return Err(GoalError::MissingGoal(from));
+        }
+        if !self.goal_graph.contains_node(on) {
+            return Err(GoalError::MissingGoal(on));
+        }
+
+        let previous_edge = self.goal_graph.add_edge(from, on, GoalEdge::Depends);
+
+        if let Err(cycle) = petgraph::algo::toposort(&self.goal_graph, None) {
+            let error = self.cycle_error(cycle.node_id());
+
+            match previous_edge {
+                Some(edge) => {
+                    self.goal_graph.add_edge(from, on, edge);
+                }
+                None => {
+                    self.goal_graph.remove_edge(from, on);
+                }
+            };
+
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    fn set_status(&mut self, id: GoalId, status: Status) -> Result<(), GoalError> {
+        if !self.all_ids.contains(&id) {
+            return Err(GoalError::MissingGoal(id));
+        }
 
         match self.goal_id_to_status.entry(id) {
             Entry::Occupied(mut occ) => {
@@ -172,8 +475,8 @@ impl<GH : GoalHandler<Self>> GoalHolder for DefaultHolder<GH> {
             }
         };
 
-        let ref status = self.goal_id_to_status[&id];
-        self.goal_handler.handle_goal_status_change(goal, status);
+        let status = &self.goal_id_to_status[&id];
+        self.goal_handler.handle_goal_status_change(id, status);
 
         Ok(())
     }
@@ -186,8 +489,12 @@ impl<GH : GoalHandler<Self>> GoalHolder for DefaultHolder<GH> {
         if !self.goal_graph.contains_node(*id) {
             return Err(GoalError::MissingGoal(*id));
         }
-        let directed = self.goal_graph.neighbors_directed(*id, Direction::Incoming);
-        let mut result: Vec<_> = directed.collect();
+        let mut result: Vec<_> = self
+            .goal_graph
+            .edges_directed(*id, Direction::Incoming)
+            .filter(|(_, _, &edge)| edge == GoalEdge::Parent)
+            .map(|(source, _, _)| source)
+            .collect();
         if result.len() > 1 {
             panic!(
                 "Can not have more than one parent (parents = {})",
@@ -205,4 +512,181 @@ impl<GH : GoalHandler<Self>> GoalHolder for DefaultHolder<GH> {
         let directed = self.goal_graph.neighbors_directed(*id, Direction::Outgoing);
         Ok(directed.collect())
     }
+
+    fn topological_order(&self) -> Result<Vec<GoalId>, GoalError> {
+        // Edges point from a goal to what it depends on, so a raw toposort
+        // lists dependents before their dependencies; reverse it to get a
+        // runnable order (dependencies first).
+        let mut order = petgraph::algo::toposort(&self.goal_graph, None)
+            .map_err(|cycle| self.cycle_error(cycle.node_id()))?;
+        order.reverse();
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goal::{DefaultGoal, Goal};
+    use crate::view::GoalContainer;
+
+    fn root_id(container: &GoalContainer<DefaultHolder>) -> GoalId {
+        let holder = container.holder().read().expect("holder poisoned");
+        *holder
+            .get_goal_id(container.root_goal().name())
+            .expect("root goal was never registered")
+    }
+
+    #[test]
+    fn add_dependency_does_not_change_get_parent() {
+        let container = GoalContainer::new("root", DefaultHolder::default());
+        let holder = container.holder().clone();
+        let root = root_id(&container);
+
+        let a = DefaultGoal::new("a", &root, &holder);
+        let a_id = holder.write().unwrap().add_goal(&a).unwrap();
+
+        let b = DefaultGoal::new("b", &root, &holder);
+        let b_id = holder.write().unwrap().add_goal(&b).unwrap();
+
+        holder.write().unwrap().add_dependency(b_id, a_id).unwrap();
+
+        let guard = holder.read().unwrap();
+        assert_eq!(guard.get_parent(&a_id).unwrap(), root);
+        assert_eq!(
+            guard.get_parent(&b_id).unwrap(),
+            root,
+            "a Depends edge must not change b's Parent-derived parent"
+        );
+    }
+
+    #[test]
+    fn add_goal_rejecting_a_cycle_leaves_the_holder_unchanged() {
+        let container = GoalContainer::new("root", DefaultHolder::default());
+        let holder = container.holder().clone();
+        let root = root_id(&container);
+
+        let a = DefaultGoal::new("a", &root, &holder);
+        let a_id = holder.write().unwrap().add_goal(&a).unwrap();
+
+        let mut b = DefaultGoal::new("b", &root, &holder);
+        b.depend_on(a_id);
+        let b_id = holder.write().unwrap().add_goal(&b).unwrap();
+
+        let goals_before = holder.read().unwrap().all_goals().len();
+
+        // `e`'s parent is `a` (a -> e) and it depends on `b` (e -> b), which
+        // together with the existing `b -> a` dependency closes a cycle.
+        let mut e = DefaultGoal::new("e", &a_id, &holder);
+        e.depend_on(b_id);
+        let result = holder.write().unwrap().add_goal(&e);
+
+        assert!(matches!(result, Err(GoalError::CycleDetected(_))));
+        let guard = holder.read().unwrap();
+        assert_eq!(guard.all_goals().len(), goals_before);
+        assert!(guard.get_goal_id(&"e".to_string()).is_err());
+    }
+
+    #[test]
+    fn add_dependency_rejecting_a_cycle_leaves_the_holder_unchanged() {
+        let container = GoalContainer::new("root", DefaultHolder::default());
+        let holder = container.holder().clone();
+        let root = root_id(&container);
+
+        let a = DefaultGoal::new("a", &root, &holder);
+        let a_id = holder.write().unwrap().add_goal(&a).unwrap();
+
+        let mut b = DefaultGoal::new("b", &root, &holder);
+        b.depend_on(a_id);
+        let b_id = holder.write().unwrap().add_goal(&b).unwrap();
+
+        let children_before = holder.read().unwrap().get_direct_children(&a_id).unwrap();
+
+        let result = holder.write().unwrap().add_dependency(a_id, b_id);
+
+        assert!(matches!(result, Err(GoalError::CycleDetected(_))));
+        assert_eq!(
+            holder.read().unwrap().get_direct_children(&a_id).unwrap(),
+            children_before,
+            "a rejected add_dependency must not leave behind a partial edge"
+        );
+    }
+
+    #[test]
+    fn aggregate_status_joins_mixed_children() {
+        let container = GoalContainer::new("root", DefaultHolder::default());
+        let holder = container.holder().clone();
+        let root = root_id(&container);
+
+        let a = DefaultGoal::new("a", &root, &holder);
+        let a_id = holder.write().unwrap().add_goal(&a).unwrap();
+        let b = DefaultGoal::new("b", &root, &holder);
+        let b_id = holder.write().unwrap().add_goal(&b).unwrap();
+
+        {
+            let mut guard = holder.write().unwrap();
+            guard.set_status(a_id, Status::Finished(Outcome::Success)).unwrap();
+            guard.set_status(b_id, Status::Finished(Outcome::Skipped)).unwrap();
+        }
+
+        let guard = holder.read().unwrap();
+        assert!(matches!(
+            guard.aggregate_status(root).unwrap(),
+            Status::Finished(Outcome::Success)
+        ));
+    }
+
+    #[test]
+    fn aggregate_status_reflects_a_goal_started_before_its_children() {
+        let container = GoalContainer::new("root", DefaultHolder::default());
+        let holder = container.holder().clone();
+        let root = root_id(&container);
+
+        let parent = DefaultGoal::new("parent", &root, &holder);
+        let parent_id = holder.write().unwrap().add_goal(&parent).unwrap();
+        let child = DefaultGoal::new("child", &parent_id, &holder);
+        holder.write().unwrap().add_goal(&child).unwrap();
+
+        // The child is still untouched (`Waiting`), but `parent` itself was
+        // explicitly started: its aggregate must reflect that rather than
+        // purely joining its untouched children.
+        holder
+            .write()
+            .unwrap()
+            .set_status(parent_id, Status::InProgress)
+            .unwrap();
+
+        let guard = holder.read().unwrap();
+        assert!(matches!(
+            guard.aggregate_status(parent_id).unwrap(),
+            Status::InProgress
+        ));
+    }
+
+    #[test]
+    fn aggregate_status_lets_an_explicit_finish_dominate_unfinished_children() {
+        let container = GoalContainer::new("root", DefaultHolder::default());
+        let holder = container.holder().clone();
+        let root = root_id(&container);
+
+        let parent = DefaultGoal::new("parent", &root, &holder);
+        let parent_id = holder.write().unwrap().add_goal(&parent).unwrap();
+        let child = DefaultGoal::new("child", &parent_id, &holder);
+        holder.write().unwrap().add_goal(&child).unwrap();
+
+        // `parent` fails itself directly without ever running `child`; the
+        // explicit failure must dominate rather than being diluted by the
+        // still-`Waiting` child back down to `Waiting`.
+        holder
+            .write()
+            .unwrap()
+            .set_status(parent_id, Status::Finished(Outcome::Failed(Box::new(crate::status::DerivedFailure))))
+            .unwrap();
+
+        let guard = holder.read().unwrap();
+        assert!(matches!(
+            guard.aggregate_status(parent_id).unwrap(),
+            Status::Finished(Outcome::Failed(_))
+        ));
+    }
 }
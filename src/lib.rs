@@ -0,0 +1,6 @@
+pub mod driver;
+pub mod goal;
+pub mod holder;
+pub mod progress;
+pub mod status;
+pub mod view;
@@ -1,33 +1,310 @@
+use std::collections::{HashMap, VecDeque};
+use std::io::{self, Read, Stdout};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::RwLock;
-use crate::holder::DefaultHolder;
-use crate::view::GoalContainer;
-use lazy_static::lazy_static;
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
 use gag::BufferRedirect;
+use lazy_static::lazy_static;
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Terminal;
+
+use crate::goal::GoalId;
+use crate::holder::DefaultHolder;
+use crate::status::{Outcome, Status};
+use crate::view::GoalHandler;
 
 lazy_static! {
     static ref REDIRECTED_OUTPUT: RwLock<Option<BufferRedirect>> = RwLock::new(None);
 }
 
+const SPINNER_FRAMES: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+const SPINNER_INTERVAL: Duration = Duration::from_millis(80);
+const OUTPUT_TAIL_LINES: usize = 10;
 
 pub trait ProgressView {
-    fn start_progress(&mut self) {
-        let result = REDIRECTED_OUTPUT.write().unwrap();
-        *result = Some(BufferRedirect::stdout().unwrap());
+    fn start_progress(&mut self)
+    where
+        Self: Sized,
+    {
+        let mut redirected = REDIRECTED_OUTPUT.write().unwrap();
+        *redirected = Some(BufferRedirect::stdout().unwrap());
     }
 
-    fn end_progress(self) {
-        let result = REDIRECTED_OUTPUT.write().unwrap();
-        let buffer = std::mem::replace(&mut *result, None);
+    fn end_progress(self)
+    where
+        Self: Sized,
+    {
+        let mut redirected = REDIRECTED_OUTPUT.write().unwrap();
+        let buffer = std::mem::replace(&mut *redirected, None);
         drop(buffer);
     }
 }
 
+/// One rendered line of the goal tree.
+#[derive(Debug, Clone)]
+struct GoalLine {
+    name: String,
+    depth: usize,
+    state: LineState,
+}
 
-struct ProgressViewHolder<P : ProgressView> {
-    view: P
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LineState {
+    Waiting,
+    InProgress,
+    Success,
+    Failed,
+    Skipped,
 }
 
+impl LineState {
+    fn from_status(status: &Status) -> Self {
+        match status {
+            Status::Waiting => LineState::Waiting,
+            Status::InProgress => LineState::InProgress,
+            Status::Finished(Outcome::Success) => LineState::Success,
+            Status::Finished(Outcome::Failed(_)) => LineState::Failed,
+            Status::Finished(Outcome::Skipped) => LineState::Skipped,
+        }
+    }
+
+    fn glyph(self, spinner_frame: usize) -> char {
+        match self {
+            LineState::Waiting => '·',
+            LineState::InProgress => SPINNER_FRAMES[spinner_frame % SPINNER_FRAMES.len()],
+            LineState::Success => '✓',
+            LineState::Failed => '✗',
+            LineState::Skipped => '⊘',
+        }
+    }
+
+    fn style(self) -> Style {
+        match self {
+            LineState::Waiting => Style::default().add_modifier(Modifier::DIM),
+            LineState::InProgress => Style::default().fg(Color::Yellow),
+            LineState::Success => Style::default().fg(Color::Green),
+            LineState::Failed => Style::default().fg(Color::Red),
+            LineState::Skipped => Style::default().add_modifier(Modifier::DIM),
+        }
+    }
+}
+
+/// Terminal and tree state shared between the handler callbacks (which
+/// mutate individual lines) and the ticker thread (which animates spinners
+/// and repaints on an interval).
+struct RenderState {
+    terminal: Terminal<CrosstermBackend<Stdout>>,
+    lines: Vec<GoalLine>,
+    spinner_frame: usize,
+    output_tail: VecDeque<String>,
+}
+
+impl RenderState {
+    fn pull_output(&mut self) {
+        let mut redirected = REDIRECTED_OUTPUT.write().unwrap();
+        if let Some(buffer) = redirected.as_mut() {
+            let mut chunk = String::new();
+            if buffer.read_to_string(&mut chunk).is_ok() {
+                for line in chunk.lines() {
+                    self.output_tail.push_back(line.to_string());
+                }
+                while self.output_tail.len() > OUTPUT_TAIL_LINES {
+                    self.output_tail.pop_front();
+                }
+            }
+        }
+    }
+
+    fn render(&mut self) {
+        let spinner_frame = self.spinner_frame;
+        let tree: Vec<Line> = self
+            .lines
+            .iter()
+            .map(|line| {
+                let indent = "  ".repeat(line.depth);
+                let glyph = line.state.glyph(spinner_frame);
+                Line::from(Span::styled(
+                    format!("{indent}{glyph} {}", line.name),
+                    line.state.style(),
+                ))
+            })
+            .collect();
+
+        let output: Vec<Line> = self
+            .output_tail
+            .iter()
+            .map(|line| Line::from(line.as_str()))
+            .collect();
+
+        self.terminal
+            .draw(|frame| {
+                let area = frame.size();
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([
+                        Constraint::Min(0),
+                        Constraint::Length(OUTPUT_TAIL_LINES as u16 + 2),
+                    ])
+                    .split(area);
+
+                let tree_widget = Paragraph::new(tree)
+                    .block(Block::default().borders(Borders::ALL).title("Goals"));
+                frame.render_widget(tree_widget, chunks[0]);
+
+                let output_widget = Paragraph::new(output)
+                    .block(Block::default().borders(Borders::ALL).title("Output"));
+                frame.render_widget(output_widget, chunks[1]);
+            })
+            .ok();
+    }
+}
+
+/// Live TUI progress view, rendered with ratatui + crossterm.
+///
+/// Repaints a tree of the goal graph: each goal is a line indented by depth,
+/// shown with a spinner while `InProgress`, a ✓/✗/⊘ glyph once `Finished`,
+/// and a dimmed marker while still `Waiting`. A timer thread redraws on an
+/// interval so spinners animate independently of goal status changes, and
+/// captured stdout scrolls in a pane underneath the tree.
+///
+/// Registers itself as a [`DefaultHolder`]'s [`GoalHandler`] — pass it to
+/// [`DefaultHolder::new`] directly rather than trying to build it from a
+/// [`crate::view::GoalContainer`], since the container's holder needs this
+/// view to already exist.
 pub struct BasicProgressView {
-    goal_container: GoalContainer<DefaultHolder>
+    state: Option<Arc<Mutex<RenderState>>>,
+    stop: Option<Arc<AtomicBool>>,
+    ticker: Option<JoinHandle<()>>,
+    /// Tree depth of each registered goal, keyed by id so a newly
+    /// registered goal's depth can be derived from its parent's.
+    depths: HashMap<GoalId, usize>,
+    /// Index into the rendered `lines` for each registered goal, in
+    /// registration order, so a status change can find its line without a
+    /// name-based scan.
+    line_index: HashMap<GoalId, usize>,
+}
+
+impl BasicProgressView {
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            stop: None,
+            ticker: None,
+            depths: HashMap::new(),
+            line_index: HashMap::new(),
+        }
+    }
+}
+
+impl Default for BasicProgressView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProgressView for BasicProgressView {
+    fn start_progress(&mut self) {
+        let mut redirected = REDIRECTED_OUTPUT.write().unwrap();
+        *redirected = Some(BufferRedirect::stdout().unwrap());
+        drop(redirected);
+
+        enable_raw_mode().expect("Failed to enable raw terminal mode");
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen).expect("Failed to enter alternate screen");
+        let terminal =
+            Terminal::new(CrosstermBackend::new(stdout)).expect("Failed to start terminal");
+
+        let state = Arc::new(Mutex::new(RenderState {
+            terminal,
+            lines: Vec::new(),
+            spinner_frame: 0,
+            output_tail: VecDeque::new(),
+        }));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let ticker_state = state.clone();
+        let ticker_stop = stop.clone();
+        let ticker = thread::spawn(move || {
+            while !ticker_stop.load(Ordering::Relaxed) {
+                {
+                    let mut guard = ticker_state.lock().expect("render state poisoned");
+                    guard.spinner_frame = guard.spinner_frame.wrapping_add(1);
+                    guard.pull_output();
+                    guard.render();
+                }
+                thread::sleep(SPINNER_INTERVAL);
+            }
+        });
+
+        self.state = Some(state);
+        self.stop = Some(stop);
+        self.ticker = Some(ticker);
+    }
+
+    fn end_progress(mut self) {
+        if let Some(stop) = self.stop.take() {
+            stop.store(true, Ordering::Relaxed);
+        }
+        if let Some(ticker) = self.ticker.take() {
+            ticker.join().ok();
+        }
+
+        if let Some(state) = self.state.take() {
+            let mut guard = state.lock().expect("render state poisoned");
+            disable_raw_mode().ok();
+            execute!(guard.terminal.backend_mut(), LeaveAlternateScreen).ok();
+        }
+
+        let mut redirected = REDIRECTED_OUTPUT.write().unwrap();
+        let buffer = std::mem::replace(&mut *redirected, None);
+        drop(buffer);
+    }
+}
+
+impl GoalHandler<DefaultHolder<BasicProgressView>> for BasicProgressView {
+    fn register_goal(&mut self, id: GoalId, name: &str, parent: Option<GoalId>) {
+        let depth = parent
+            .and_then(|p| self.depths.get(&p))
+            .map(|d| d + 1)
+            .unwrap_or(0);
+        self.depths.insert(id, depth);
+
+        let Some(state) = self.state.clone() else {
+            return;
+        };
+
+        let mut guard = state.lock().expect("render state poisoned");
+        let index = guard.lines.len();
+        guard.lines.push(GoalLine {
+            name: name.to_string(),
+            depth,
+            state: LineState::Waiting,
+        });
+        self.line_index.insert(id, index);
+        guard.render();
+    }
+
+    fn handle_goal_status_change(&mut self, id: GoalId, status: &Status) {
+        let Some(state) = self.state.clone() else {
+            return;
+        };
+        let Some(&index) = self.line_index.get(&id) else {
+            return;
+        };
+
+        let mut guard = state.lock().expect("render state poisoned");
+        if let Some(line) = guard.lines.get_mut(index) {
+            line.state = LineState::from_status(status);
+        }
+        guard.render();
+    }
 }
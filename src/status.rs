@@ -1,10 +1,11 @@
 use std::error::Error;
+use thiserror::Error as ThisError;
 
 #[derive(Debug)]
 pub enum Outcome {
     Skipped,
     Success,
-    Failed(Box<dyn Error>),
+    Failed(Box<dyn Error + Send + Sync>),
 }
 
 #[derive(Debug)]
@@ -13,3 +14,11 @@ pub enum Status {
     InProgress,
     Finished(Outcome),
 }
+
+/// Stands in for a real error when a goal's `Finished(Failed)` status is
+/// derived (e.g. by [`crate::holder::GoalHolder::aggregate_status`]) rather
+/// than reported directly by the goal itself, since the original error
+/// can't be cloned out of the failing descendant.
+#[derive(Debug, ThisError)]
+#[error("a dependency failed")]
+pub struct DerivedFailure;
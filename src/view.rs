@@ -14,6 +14,10 @@ impl<H: GoalHolder> GoalContainer<H> {
     pub fn new(name: impl AsRef<str>, holder: H) -> Self {
         let arc = Arc::new(RwLock::new(holder));
         let goal = RootGoal::new(name, &arc);
+        arc.write()
+            .expect("Failed to get holder (poisoned)")
+            .add_goal(&goal)
+            .expect("Registering the root goal created a cycle in the goal graph");
         Self {
             holder: arc,
             root_goal: goal
@@ -28,6 +32,10 @@ impl<H: GoalHolder> GoalContainer<H> {
         &mut self.root_goal
     }
 
+    pub(crate) fn holder(&self) -> &Arc<RwLock<H>> {
+        &self.holder
+    }
+
     pub fn all_goals(&self) -> impl IntoIterator<Item=GoalId> {
         let holder = self.holder.read().unwrap();
         let goal_id = holder.get_goal_id(self.root_goal.name()).unwrap();
@@ -36,8 +44,33 @@ impl<H: GoalHolder> GoalContainer<H> {
 
 }
 
-pub trait GoalHandler<H : GoalHolder> {
-    fn register_goals(&mut self, goals: impl IntoIterator<Item=GoalId>);
-    fn handle_goal_status_change<G : Goal<H>>(&mut self, goal: &G, status: &Status);
+/// Observes goal registration and status changes reported by a [`GoalHolder`].
+///
+/// Callbacks are id-based rather than taking a live [`Goal`] object: by the
+/// time [`GoalHolder::set_status`] fires, the `Goal` that triggered it (if
+/// any — [`crate::driver::Driver`] never constructs one at all) has usually
+/// already been consumed. A handler that needs a goal's name or tree
+/// position gets it from `register_goal`'s `name`/`parent` and tracks
+/// whatever it needs against `id` itself.
+pub trait GoalHandler<H: GoalHolder> {
+    /// Called once, right after `id` is added to the holder via
+    /// [`GoalHolder::add_goal`].
+    fn register_goal(&mut self, id: GoalId, name: &str, parent: Option<GoalId>);
+    /// Called right after `id`'s status changes via [`GoalHolder::set_status`].
+    fn handle_goal_status_change(&mut self, id: GoalId, status: &Status);
+}
+
+/// A [`GoalHandler`] that ignores every callback.
+///
+/// This is the default handler for [`crate::holder::DefaultHolder`], for
+/// callers that don't need to observe goal status changes through it (e.g.
+/// because they're driving the graph through [`crate::driver::Driver`]
+/// instead).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopGoalHandler;
+
+impl GoalHandler<DefaultHolder<NoopGoalHandler>> for NoopGoalHandler {
+    fn register_goal(&mut self, _id: GoalId, _name: &str, _parent: Option<GoalId>) {}
+    fn handle_goal_status_change(&mut self, _id: GoalId, _status: &Status) {}
 }
 